@@ -0,0 +1,254 @@
+//! Leader election built on top of etcd's Election service.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use futures::Stream;
+
+use crate::lease::LeaseId;
+use crate::proto::v3electionpb;
+use crate::Result;
+
+pub trait ElectionOp {
+    /// Puts a value as eligible for the election named by `req`, blocking until it is elected
+    /// leader (i.e. holds the earliest-created key for that name).
+    ///
+    /// Binding the campaign to a lease (via [`CampaignRequest::lease`]) means leadership is
+    /// relinquished automatically if the holder's lease expires, without requiring an explicit
+    /// [`resign`](ElectionOp::resign).
+    fn campaign<R>(&self, req: R) -> impl Future<Output = Result<CampaignResponse>>
+    where
+        R: Into<CampaignRequest>;
+
+    /// Updates the value of the election's leader, without requiring another campaign.
+    fn proclaim<R>(&self, req: R) -> impl Future<Output = Result<ProclaimResponse>>
+    where
+        R: Into<ProclaimRequest>;
+
+    /// Returns the current leader of the election named `name`, without campaigning for it.
+    fn leader<N>(&self, name: N) -> impl Future<Output = Result<LeaderResponse>>
+    where
+        N: Into<Vec<u8>>;
+
+    /// Resigns leadership, releasing the key returned by [`campaign`](ElectionOp::campaign).
+    fn resign<R>(&self, req: R) -> impl Future<Output = Result<ResignResponse>>
+    where
+        R: Into<ResignRequest>;
+
+    /// Streams the leader of the election named `name`, yielding a new value each time
+    /// leadership changes.
+    fn observe<N>(&self, name: N) -> Pin<Box<dyn Stream<Item = Result<LeaderResponse>> + Send>>
+    where
+        N: Into<Vec<u8>>;
+}
+
+/// Identifies a held leadership, returned by [`ElectionOp::campaign`] and required to
+/// [`proclaim`](ElectionOp::proclaim) or [`resign`](ElectionOp::resign).
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct LeaderKey {
+    /// The election's name.
+    pub name: Vec<u8>,
+    /// The unique key holding this leadership.
+    pub key: Vec<u8>,
+    /// The creation revision of `key`.
+    pub rev: i64,
+    /// The lease attached to `key`, if any.
+    pub lease: LeaseId,
+}
+
+impl From<v3electionpb::LeaderKey> for LeaderKey {
+    fn from(proto: v3electionpb::LeaderKey) -> Self {
+        Self {
+            name: proto.name,
+            key: proto.key,
+            rev: proto.rev,
+            lease: proto.lease.into(),
+        }
+    }
+}
+
+impl From<LeaderKey> for v3electionpb::LeaderKey {
+    fn from(leader: LeaderKey) -> Self {
+        Self {
+            name: leader.name,
+            key: leader.key,
+            rev: leader.rev,
+            lease: leader.lease.into(),
+        }
+    }
+}
+
+/// Request to campaign for leadership of an election, via [`ElectionOp::campaign`].
+#[derive(Clone, Debug)]
+pub struct CampaignRequest {
+    proto: v3electionpb::CampaignRequest,
+}
+
+impl CampaignRequest {
+    /// Creates a new `CampaignRequest` for the election named `name`, proclaiming `value` if
+    /// elected.
+    pub fn new<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        Self {
+            proto: v3electionpb::CampaignRequest {
+                name: name.into(),
+                value: value.into(),
+                lease: 0,
+            },
+        }
+    }
+
+    /// Binds the campaign to a lease, so leadership is released automatically if the lease
+    /// expires.
+    pub fn lease(mut self, lease_id: LeaseId) -> Self {
+        self.proto.lease = lease_id.into();
+        self
+    }
+}
+
+impl From<CampaignRequest> for v3electionpb::CampaignRequest {
+    fn from(req: CampaignRequest) -> Self {
+        req.proto
+    }
+}
+
+/// Response to a [`ElectionOp::campaign`] call.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CampaignResponse {
+    /// The key identifying this campaign's leadership, to be used with
+    /// [`proclaim`](ElectionOp::proclaim) and [`resign`](ElectionOp::resign).
+    pub leader: LeaderKey,
+}
+
+impl From<v3electionpb::CampaignResponse> for CampaignResponse {
+    fn from(proto: v3electionpb::CampaignResponse) -> Self {
+        Self {
+            leader: proto
+                .leader
+                .expect("etcd server always sets leader on a successful CampaignResponse")
+                .into(),
+        }
+    }
+}
+
+/// Request to update the value proclaimed by an election's leader, via
+/// [`ElectionOp::proclaim`].
+#[derive(Clone, Debug)]
+pub struct ProclaimRequest {
+    proto: v3electionpb::ProclaimRequest,
+}
+
+impl ProclaimRequest {
+    /// Creates a new `ProclaimRequest` that sets `leader`'s election to `value`.
+    ///
+    /// `leader` must be the key returned by the [`CampaignResponse`] that won this leadership.
+    pub fn new<V>(leader: LeaderKey, value: V) -> Self
+    where
+        V: Into<Vec<u8>>,
+    {
+        Self {
+            proto: v3electionpb::ProclaimRequest {
+                leader: Some(leader.into()),
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl From<ProclaimRequest> for v3electionpb::ProclaimRequest {
+    fn from(req: ProclaimRequest) -> Self {
+        req.proto
+    }
+}
+
+/// Response to a [`ElectionOp::proclaim`] call.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ProclaimResponse {}
+
+impl From<v3electionpb::ProclaimResponse> for ProclaimResponse {
+    fn from(_proto: v3electionpb::ProclaimResponse) -> Self {
+        Self {}
+    }
+}
+
+/// Response to a [`ElectionOp::leader`] call, or a single item of [`ElectionOp::observe`]'s stream.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct LeaderResponse {
+    /// The current value proclaimed by the leader.
+    pub value: Vec<u8>,
+    pub leader: LeaderKey,
+}
+
+impl LeaderResponse {
+    /// Builds a `LeaderResponse` for the election named `name` from the raw proto response.
+    ///
+    /// `v3electionpb::LeaderResponse` doesn't echo the election's name back (the server already
+    /// knows which election it answered for), so unlike the other `From<proto> for _` impls in
+    /// this module, the caller must supply it explicitly instead of it being dropped.
+    pub(crate) fn from_proto<N>(name: N, proto: v3electionpb::LeaderResponse) -> Self
+    where
+        N: Into<Vec<u8>>,
+    {
+        // The server only returns a successful LeaderResponse when a leader exists (an
+        // unelected election surfaces as an RPC error instead), so `kv` is always set here.
+        // Still `.expect()` rather than trust that blindly: `kv` reflects what the remote etcd
+        // server put on the wire, not something this process controls.
+        let kv = proto
+            .kv
+            .expect("etcd server always sets kv on a successful LeaderResponse");
+
+        Self {
+            value: kv.value,
+            leader: LeaderKey {
+                name: name.into(),
+                key: kv.key,
+                rev: kv.create_revision,
+                lease: kv.lease.into(),
+            },
+        }
+    }
+}
+
+/// Request to resign a held leadership, via [`ElectionOp::resign`].
+#[derive(Clone, Debug)]
+pub struct ResignRequest {
+    proto: v3electionpb::ResignRequest,
+}
+
+impl ResignRequest {
+    /// Creates a new `ResignRequest` for the leadership identified by `leader`.
+    ///
+    /// `leader` must be the key returned by the [`CampaignResponse`] that won this leadership.
+    pub fn new(leader: LeaderKey) -> Self {
+        Self {
+            proto: v3electionpb::ResignRequest {
+                leader: Some(leader.into()),
+            },
+        }
+    }
+}
+
+impl From<ResignRequest> for v3electionpb::ResignRequest {
+    fn from(req: ResignRequest) -> Self {
+        req.proto
+    }
+}
+
+impl From<LeaderKey> for ResignRequest {
+    fn from(leader: LeaderKey) -> Self {
+        Self::new(leader)
+    }
+}
+
+/// Response to a [`ElectionOp::resign`] call.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ResignResponse {}
+
+impl From<v3electionpb::ResignResponse> for ResignResponse {
+    fn from(_proto: v3electionpb::ResignResponse) -> Self {
+        Self {}
+    }
+}
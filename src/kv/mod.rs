@@ -2,6 +2,7 @@ mod compact;
 mod delete;
 mod put;
 mod range;
+mod scan;
 mod txn;
 
 pub use compact::{CompactRequest, CompactResponse};
@@ -10,7 +11,12 @@ pub use put::{PutRequest, PutResponse};
 pub use range::{RangeRequest, RangeResponse};
 pub use txn::{TxnCmp, TxnOp, TxnOpResponse, TxnRequest, TxnResponse};
 
-use std::{future::Future, ops::Range};
+use std::{
+    future::Future,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use futures::Stream;
 
 use crate::lease::LeaseId;
 use crate::proto::mvccpb;
@@ -52,6 +58,30 @@ pub trait KeyValueOp {
     fn compact<R>(&self, req: R) -> impl Future<Output = Result<CompactResponse>>
     where
         R: Into<CompactRequest>;
+
+    /// Streams every key-value pair matching `req`, auto-paging through bounded-size `get`
+    /// calls instead of loading the whole range into a single `RangeResponse`. Composes with
+    /// [`RangeRequest`]'s projection and revision options: the first page's revision is pinned
+    /// and reused for every later page, so the stream reads one consistent snapshot even though
+    /// it issues many requests. Pass a `RangeRequest` with an explicit `revision` to pin the
+    /// scan to a revision you already know, instead of the one observed on its first page.
+    ///
+    /// `req` must not request a sort other than no-sort or `SortTarget::Key` /
+    /// `SortOrder::Ascend`: the pagination cursor resumes from the last key of each page, so
+    /// any other order would silently drop keys that don't fall after it. See
+    /// [`RangeRequest::sort_by`]: only `sort_by(SortTarget::Key, SortOrder::Ascend)` is
+    /// compatible with `scan`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `req` specifies a sort other than no-sort or key-ascending.
+    fn scan<R>(&self, req: R) -> impl Stream<Item = Result<KeyValue>>
+    where
+        R: Into<RangeRequest>,
+        Self: Sized,
+    {
+        scan::scan(req.into(), move |req| self.get(req))
+    }
 }
 
 /// Key-Value pair.
@@ -159,12 +189,113 @@ impl KeyRange {
     }
 }
 
+/// A key bound used to convert idiomatic Rust ranges into etcd's half-open `(key, range_end)`
+/// representation. `Unbounded` is conceptually "+infinity": an affine bound above every
+/// concrete key, used wherever a range has no upper end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum BytesAffine {
+    Bytes(Vec<u8>),
+    Unbounded,
+}
+
+impl BytesAffine {
+    /// Turns an inclusive upper bound into the exclusive `range_end` etcd expects: the
+    /// immediate lexicographic successor of `self`, i.e. `self` with a single `0x00` byte
+    /// appended. This is *not* `KeyRange::prefix`'s carry-increment (that computes a prefix
+    /// boundary, which would wrongly also admit longer keys like `b"mm"` for an upper bound of
+    /// `b"m"`); appending a byte is always strictly greater than `self` and nothing shorter can
+    /// fall between them, so it has no "no successor" case to fall back to `Unbounded` for.
+    fn increment(self) -> Self {
+        match self {
+            BytesAffine::Unbounded => BytesAffine::Unbounded,
+            BytesAffine::Bytes(mut bytes) => {
+                bytes.push(0);
+                BytesAffine::Bytes(bytes)
+            }
+        }
+    }
+
+    /// Renders this bound as etcd's `range_end`, where `Unbounded` is `[0]`: "up to the max key".
+    fn into_range_end(self) -> Vec<u8> {
+        match self {
+            BytesAffine::Bytes(bytes) => bytes,
+            BytesAffine::Unbounded => vec![0],
+        }
+    }
+}
+
+/// Builds a `KeyRange` from a start bound (`Unbounded` meaning "the lowest key") and an end
+/// bound already converted to etcd's `range_end` convention.
+fn bounded_range(start: BytesAffine, range_end: BytesAffine) -> KeyRange {
+    let key = match start {
+        BytesAffine::Bytes(bytes) => bytes,
+        // There's no key below every key, so reuse the same sentinel as `KeyRange::all`.
+        BytesAffine::Unbounded => vec![0],
+    };
+    KeyRange {
+        key,
+        range_end: range_end.into_range_end(),
+    }
+}
+
 impl<T> From<Range<T>> for KeyRange
 where
     T: Into<Vec<u8>>,
 {
     fn from(range: Range<T>) -> Self {
-        Self::range(range.start, range.end)
+        bounded_range(
+            BytesAffine::Bytes(range.start.into()),
+            BytesAffine::Bytes(range.end.into()),
+        )
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for KeyRange
+where
+    T: Into<Vec<u8>>,
+{
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        bounded_range(
+            BytesAffine::Bytes(start.into()),
+            BytesAffine::Bytes(end.into()).increment(),
+        )
+    }
+}
+
+impl<T> From<RangeFrom<T>> for KeyRange
+where
+    T: Into<Vec<u8>>,
+{
+    fn from(range: RangeFrom<T>) -> Self {
+        bounded_range(BytesAffine::Bytes(range.start.into()), BytesAffine::Unbounded)
+    }
+}
+
+impl<T> From<RangeTo<T>> for KeyRange
+where
+    T: Into<Vec<u8>>,
+{
+    fn from(range: RangeTo<T>) -> Self {
+        bounded_range(BytesAffine::Unbounded, BytesAffine::Bytes(range.end.into()))
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for KeyRange
+where
+    T: Into<Vec<u8>>,
+{
+    fn from(range: RangeToInclusive<T>) -> Self {
+        bounded_range(
+            BytesAffine::Unbounded,
+            BytesAffine::Bytes(range.end.into()).increment(),
+        )
+    }
+}
+
+impl From<RangeFull> for KeyRange {
+    fn from(_: RangeFull) -> Self {
+        KeyRange::all()
     }
 }
 
@@ -179,3 +310,20 @@ impl From<String> for KeyRange {
         Self::key(k)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inclusive_range_excludes_keys_with_end_as_prefix() {
+        let range: KeyRange = (b"a".to_vec()..=b"m".to_vec()).into();
+
+        // The inclusive bound "m" must exclude "mm", "mz", "m\xff", etc; only "m" itself (and
+        // keys below it) are in range. This would fail with a prefix-carry increment, which
+        // bumps "m" to "n" and so admits every key prefixed by "m".
+        assert_eq!(range.range_end, b"m\0".to_vec());
+        assert!(range.range_end.as_slice() < b"mm".as_slice());
+        assert!(range.range_end.as_slice() < b"mz".as_slice());
+    }
+}
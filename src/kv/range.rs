@@ -0,0 +1,237 @@
+use crate::kv::{KeyRange, KeyValue};
+use crate::proto::etcdserverpb;
+
+/// Controls whether and how the keys returned by a [`RangeRequest`] are sorted by the server.
+///
+/// Mirrors `etcdserverpb::range_request::SortOrder`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// No sorting is applied; keys are returned in etcd's natural (ascending key) order.
+    None,
+    /// Sort ascending by `SortTarget`.
+    Ascend,
+    /// Sort descending by `SortTarget`.
+    Descend,
+}
+
+impl From<SortOrder> for i32 {
+    fn from(order: SortOrder) -> Self {
+        match order {
+            SortOrder::None => 0,
+            SortOrder::Ascend => 1,
+            SortOrder::Descend => 2,
+        }
+    }
+}
+
+/// The field used to order results when a [`SortOrder`] other than `None` is requested.
+///
+/// Mirrors `etcdserverpb::range_request::SortTarget`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortTarget {
+    Key,
+    Version,
+    Create,
+    Mod,
+    Value,
+}
+
+impl From<SortTarget> for i32 {
+    fn from(target: SortTarget) -> Self {
+        match target {
+            SortTarget::Key => 0,
+            SortTarget::Version => 1,
+            SortTarget::Create => 2,
+            SortTarget::Mod => 3,
+            SortTarget::Value => 4,
+        }
+    }
+}
+
+/// Request for fetching one or more key-value pairs.
+#[derive(Clone, Debug)]
+pub struct RangeRequest {
+    proto: etcdserverpb::RangeRequest,
+}
+
+impl RangeRequest {
+    /// Creates a new `RangeRequest` over the given key range.
+    pub fn new(key_range: KeyRange) -> Self {
+        Self {
+            proto: etcdserverpb::RangeRequest {
+                key: key_range.key,
+                range_end: key_range.range_end,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Orders the results returned by the server by `target`, in `order`.
+    ///
+    /// ```no_run
+    /// # use etcd_rs::{RangeRequest, SortOrder, SortTarget};
+    /// RangeRequest::prefix("user/").sort_by(SortTarget::Mod, SortOrder::Descend);
+    /// ```
+    pub fn sort_by(mut self, target: SortTarget, order: SortOrder) -> Self {
+        self.proto.sort_target = target.into();
+        self.proto.sort_order = order.into();
+        self
+    }
+
+    /// Creates a new `RangeRequest` for a single key.
+    pub fn key<K>(key: K) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self::new(KeyRange::key(key))
+    }
+
+    /// Creates a new `RangeRequest` for all keys sharing the given prefix.
+    pub fn prefix<K>(prefix: K) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self::new(KeyRange::prefix(prefix))
+    }
+
+    /// Creates a new `RangeRequest` spanning `[from, end)`.
+    pub fn range<F, E>(from: F, end: E) -> Self
+    where
+        F: Into<Vec<u8>>,
+        E: Into<Vec<u8>>,
+    {
+        Self::new(KeyRange::range(from, end))
+    }
+
+    /// Creates a new `RangeRequest` for all keys.
+    pub fn all() -> Self {
+        Self::new(KeyRange::all())
+    }
+
+    /// Limits the number of keys returned. `0` (the default) means no limit.
+    ///
+    /// Combine with [`RangeResponse::more`] to page through a large range by repeatedly
+    /// resuming from the last returned key.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.proto.limit = limit;
+        self
+    }
+
+    /// Returns only the keys, omitting values, from the response.
+    pub fn keys_only(mut self) -> Self {
+        self.proto.keys_only = true;
+        self
+    }
+
+    /// Returns only the count of keys matching the range, omitting keys and values.
+    pub fn count_only(mut self) -> Self {
+        self.proto.count_only = true;
+        self
+    }
+
+    /// Reads the range as of the given historical revision, rather than the latest revision.
+    pub fn revision(mut self, revision: i64) -> Self {
+        self.proto.revision = revision;
+        self
+    }
+
+    /// Filters out keys with a create revision lower than `revision`.
+    pub fn min_create_revision(mut self, revision: i64) -> Self {
+        self.proto.min_create_revision = revision;
+        self
+    }
+
+    /// Filters out keys with a create revision higher than `revision`.
+    pub fn max_create_revision(mut self, revision: i64) -> Self {
+        self.proto.max_create_revision = revision;
+        self
+    }
+
+    /// Filters out keys with a modify revision lower than `revision`.
+    pub fn min_mod_revision(mut self, revision: i64) -> Self {
+        self.proto.min_mod_revision = revision;
+        self
+    }
+
+    /// Filters out keys with a modify revision higher than `revision`.
+    pub fn max_mod_revision(mut self, revision: i64) -> Self {
+        self.proto.max_mod_revision = revision;
+        self
+    }
+
+    /// `true` if this request's sort is compatible with [`crate::kv::KeyValueOp::scan`]'s
+    /// pagination cursor: no sort at all, or ascending by key — the only orders under which the
+    /// last key of one page is guaranteed to precede every key of the next.
+    pub(crate) fn is_scan_compatible_sort(&self) -> bool {
+        let unsorted = self.proto.sort_order == i32::from(SortOrder::None);
+        let key_ascending = self.proto.sort_target == i32::from(SortTarget::Key)
+            && self.proto.sort_order == i32::from(SortOrder::Ascend);
+        unsorted || key_ascending
+    }
+}
+
+impl From<KeyRange> for RangeRequest {
+    fn from(key_range: KeyRange) -> Self {
+        Self::new(key_range)
+    }
+}
+
+impl From<Vec<u8>> for RangeRequest {
+    fn from(key: Vec<u8>) -> Self {
+        Self::new(KeyRange::key(key))
+    }
+}
+
+impl From<&str> for RangeRequest {
+    fn from(key: &str) -> Self {
+        Self::new(KeyRange::key(key))
+    }
+}
+
+impl From<String> for RangeRequest {
+    fn from(key: String) -> Self {
+        Self::new(KeyRange::key(key))
+    }
+}
+
+impl From<RangeRequest> for etcdserverpb::RangeRequest {
+    fn from(req: RangeRequest) -> Self {
+        req.proto
+    }
+}
+
+impl From<etcdserverpb::RangeRequest> for RangeRequest {
+    fn from(proto: etcdserverpb::RangeRequest) -> Self {
+        Self { proto }
+    }
+}
+
+/// Response returned by a [`RangeRequest`].
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct RangeResponse {
+    pub kvs: Vec<KeyValue>,
+    /// The total number of keys matching the range, regardless of `limit`.
+    pub count: i64,
+    /// `true` if there are more keys beyond the ones returned, i.e. the range was truncated
+    /// by `limit`. Resume by issuing another `RangeRequest` starting just after the last key
+    /// in `kvs`.
+    pub more: bool,
+    /// The store revision when this range was served, taken from the response header. For a
+    /// request without an explicit [`RangeRequest::revision`], this is the revision the range
+    /// was read at; for a historical read it is the store's current revision, not the requested
+    /// one. Pin a later `RangeRequest` to this value
+    /// (via [`RangeRequest::revision`]) to keep reading a consistent snapshot, e.g. across the
+    /// pages of a [`crate::kv::KeyValueOp::scan`].
+    pub revision: i64,
+}
+
+impl From<etcdserverpb::RangeResponse> for RangeResponse {
+    fn from(proto: etcdserverpb::RangeResponse) -> Self {
+        Self {
+            kvs: proto.kvs.into_iter().map(KeyValue::from).collect(),
+            count: proto.count,
+            more: proto.more,
+            revision: proto.header.map(|header| header.revision).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::future::Future;
+
+use futures::Stream;
+
+use crate::kv::{KeyValue, RangeRequest, RangeResponse};
+use crate::proto::etcdserverpb;
+use crate::Result;
+
+/// Page size used by [`KeyValueOp::scan`](crate::kv::KeyValueOp::scan) when the caller's
+/// `RangeRequest` doesn't already set a `limit`.
+const SCAN_PAGE_SIZE: i64 = 128;
+
+/// Returns the lexicographically smallest byte string strictly greater than `key`, used to
+/// resume a scan just past the last key already returned.
+fn key_after(key: &[u8]) -> Vec<u8> {
+    let mut next = key.to_vec();
+    next.push(0);
+    next
+}
+
+/// Drives repeated, bounded-`limit` `RangeRequest`s (seeded from `req`, so its projection and
+/// revision options carry over) through `get`, yielding one [`KeyValue`] at a time instead of
+/// materializing the whole range into memory. Unless `req` already sets a `revision`, the first
+/// page's revision is pinned and reused for every later page, so the whole scan reads one consistent snapshot even as the range is
+/// paged through, following the `more` flag on [`RangeResponse`] until it is exhausted.
+///
+/// The pagination cursor resumes each page just past the last *key* the previous page returned,
+/// so it only makes sense when keys come back in ascending-key order: `req` must use no sort at
+/// all, or `SortTarget::Key` with `SortOrder::Ascend`. Any other order (e.g. sorting by `Mod`
+/// revision) would make the cursor advance past a key whose neighbors in that order have no
+/// relation to what's already been consumed, silently dropping them from the stream. Panics if
+/// `req` carries an incompatible sort.
+///
+/// # Panics
+///
+/// Panics if `req` specifies a sort other than no-sort or key-ascending.
+pub(crate) fn scan<'a, G, Fut>(req: RangeRequest, get: G) -> impl Stream<Item = Result<KeyValue>> + 'a
+where
+    G: Fn(RangeRequest) -> Fut + 'a,
+    Fut: Future<Output = Result<RangeResponse>> + 'a,
+{
+    assert!(
+        req.is_scan_compatible_sort(),
+        "KeyValueOp::scan requires no sort, or SortTarget::Key with SortOrder::Ascend: \
+         any other order has no relation to the key-based pagination cursor and would \
+         silently drop results"
+    );
+
+    async_stream::try_stream! {
+        let mut proto: etcdserverpb::RangeRequest = req.into();
+        if proto.limit <= 0 {
+            proto.limit = SCAN_PAGE_SIZE;
+        }
+        let mut revision_pinned = proto.revision > 0;
+
+        loop {
+            let page = get(RangeRequest::from(proto.clone())).await?;
+
+            if !revision_pinned {
+                proto.revision = page.revision;
+                revision_pinned = true;
+            }
+
+            let more = page.more;
+            let last_key = page.kvs.last().map(|kv| kv.key.clone());
+
+            for kv in page.kvs {
+                yield kv;
+            }
+
+            match (more, last_key) {
+                (true, Some(last_key)) => proto.key = key_after(&last_key),
+                _ => break,
+            }
+        }
+    }
+}
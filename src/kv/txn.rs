@@ -0,0 +1,241 @@
+use crate::kv::{DeleteRequest, DeleteResponse, KeyRange, PutRequest, PutResponse};
+use crate::kv::{RangeRequest, RangeResponse};
+use crate::lease::LeaseId;
+use crate::proto::etcdserverpb::{self, compare};
+use crate::Result;
+
+/// Comparison operator used by a [`Compare`] guard in a transaction's `when` clause.
+///
+/// Mirrors `etcdserverpb::compare::CompareResult`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxnCmp {
+    Equal,
+    Greater,
+    Less,
+    NotEqual,
+}
+
+impl From<TxnCmp> for i32 {
+    fn from(cmp: TxnCmp) -> Self {
+        match cmp {
+            TxnCmp::Equal => 0,
+            TxnCmp::Greater => 1,
+            TxnCmp::Less => 2,
+            TxnCmp::NotEqual => 3,
+        }
+    }
+}
+
+/// A guard in a transaction's `when` clause: compares some property of a key, or every key in
+/// a range, against an expected value. The transaction only runs its `success` operations if
+/// every `Compare` holds; otherwise it runs its `failure` operations.
+#[derive(Clone, Debug)]
+pub struct Compare {
+    proto: etcdserverpb::Compare,
+}
+
+impl Compare {
+    fn new<K>(key: K, cmp: TxnCmp, target: compare::CompareTarget, target_union: compare::TargetUnion) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self {
+            proto: etcdserverpb::Compare {
+                result: cmp.into(),
+                target: target as i32,
+                key: key.into(),
+                target_union: Some(target_union),
+                range_end: vec![],
+            },
+        }
+    }
+
+    /// Compares a key's version, i.e. the number of times it has been modified since creation.
+    pub fn version<K>(key: K, cmp: TxnCmp, version: i64) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self::new(
+            key,
+            cmp,
+            compare::CompareTarget::Version,
+            compare::TargetUnion::Version(version),
+        )
+    }
+
+    /// Compares the revision at which a key was created.
+    pub fn create_revision<K>(key: K, cmp: TxnCmp, revision: i64) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self::new(
+            key,
+            cmp,
+            compare::CompareTarget::Create,
+            compare::TargetUnion::CreateRevision(revision),
+        )
+    }
+
+    /// Compares the revision at which a key was last modified.
+    pub fn mod_revision<K>(key: K, cmp: TxnCmp, revision: i64) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self::new(
+            key,
+            cmp,
+            compare::CompareTarget::Mod,
+            compare::TargetUnion::ModRevision(revision),
+        )
+    }
+
+    /// Compares a key's value, enabling compare-and-swap patterns.
+    pub fn value<K, V>(key: K, cmp: TxnCmp, value: V) -> Self
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        Self::new(
+            key,
+            cmp,
+            compare::CompareTarget::Value,
+            compare::TargetUnion::Value(value.into()),
+        )
+    }
+
+    /// Compares the lease a key is attached to.
+    pub fn lease<K>(key: K, cmp: TxnCmp, lease_id: LeaseId) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self::new(
+            key,
+            cmp,
+            compare::CompareTarget::Lease,
+            compare::TargetUnion::Lease(lease_id.into()),
+        )
+    }
+
+    /// Applies this compare over every key in `key_range`, instead of a single key, e.g. to
+    /// assert that an entire prefix is unmodified since some revision.
+    pub fn range<R>(mut self, key_range: R) -> Self
+    where
+        R: Into<KeyRange>,
+    {
+        let key_range = key_range.into();
+        self.proto.key = key_range.key;
+        self.proto.range_end = key_range.range_end;
+        self
+    }
+}
+
+impl From<Compare> for etcdserverpb::Compare {
+    fn from(compare: Compare) -> Self {
+        compare.proto
+    }
+}
+
+/// A single operation to run as part of a transaction's `success` or `failure` branch.
+#[derive(Clone, Debug)]
+pub enum TxnOp {
+    Put(PutRequest),
+    Get(RangeRequest),
+    Delete(DeleteRequest),
+    Txn(TxnRequest),
+}
+
+impl From<TxnOp> for etcdserverpb::RequestOp {
+    fn from(op: TxnOp) -> Self {
+        use etcdserverpb::request_op::Request;
+
+        let request = match op {
+            TxnOp::Put(req) => Request::RequestPut(req.into()),
+            TxnOp::Get(req) => Request::RequestRange(req.into()),
+            TxnOp::Delete(req) => Request::RequestDeleteRange(req.into()),
+            TxnOp::Txn(req) => Request::RequestTxn(req.into()),
+        };
+
+        etcdserverpb::RequestOp {
+            request: Some(request),
+        }
+    }
+}
+
+/// The result of a single [`TxnOp`] that ran as part of a transaction.
+#[derive(Clone, Debug)]
+pub enum TxnOpResponse {
+    Put(PutResponse),
+    Get(RangeResponse),
+    Delete(DeleteResponse),
+    Txn(TxnResponse),
+}
+
+impl From<etcdserverpb::ResponseOp> for TxnOpResponse {
+    fn from(proto: etcdserverpb::ResponseOp) -> Self {
+        use etcdserverpb::response_op::Response;
+
+        // `response` reflects what the remote etcd server put on the wire, not something this
+        // process controls, so `.expect()` rather than trusting the documented contract blindly.
+        match proto.response.expect("etcd server always sets a response variant") {
+            Response::ResponsePut(resp) => TxnOpResponse::Put(resp.into()),
+            Response::ResponseRange(resp) => TxnOpResponse::Get(resp.into()),
+            Response::ResponseDeleteRange(resp) => TxnOpResponse::Delete(resp.into()),
+            Response::ResponseTxn(resp) => TxnOpResponse::Txn(resp.into()),
+        }
+    }
+}
+
+/// A request that atomically compares and then mutates keys, mirroring etcd's `Txn` RPC.
+#[derive(Clone, Debug, Default)]
+pub struct TxnRequest {
+    proto: etcdserverpb::TxnRequest,
+}
+
+impl TxnRequest {
+    /// Creates a new, empty transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `when` clause: the transaction only runs `and_then` if every compare holds,
+    /// otherwise it runs `or_else`.
+    pub fn when(mut self, compares: impl IntoIterator<Item = Compare>) -> Self {
+        self.proto.compare = compares.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the operations to run if every compare in `when` holds.
+    pub fn and_then(mut self, ops: impl IntoIterator<Item = TxnOp>) -> Self {
+        self.proto.success = ops.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the operations to run if any compare in `when` fails.
+    pub fn or_else(mut self, ops: impl IntoIterator<Item = TxnOp>) -> Self {
+        self.proto.failure = ops.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl From<TxnRequest> for etcdserverpb::TxnRequest {
+    fn from(req: TxnRequest) -> Self {
+        req.proto
+    }
+}
+
+/// Response to a [`TxnRequest`].
+#[derive(Clone, Debug)]
+pub struct TxnResponse {
+    /// Whether every compare in the `when` clause held, i.e. whether `and_then` ran.
+    pub succeeded: bool,
+    pub responses: Vec<TxnOpResponse>,
+}
+
+impl From<etcdserverpb::TxnResponse> for TxnResponse {
+    fn from(proto: etcdserverpb::TxnResponse) -> Self {
+        Self {
+            succeeded: proto.succeeded,
+            responses: proto.responses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Distributed mutual exclusion built on top of etcd's Lock service.
+
+use std::future::Future;
+
+use crate::lease::LeaseId;
+use crate::proto::v3lockpb;
+use crate::Result;
+
+pub trait LockOp {
+    /// Acquires a lock, blocking until it is held.
+    ///
+    /// If `req` is bound to a lease via [`LockRequest::lease`], the lock is released
+    /// automatically when that lease expires (e.g. on client death), instead of only on an
+    /// explicit [`unlock`].
+    ///
+    /// [`unlock`]: LockOp::unlock
+    fn lock<R>(&self, req: R) -> impl Future<Output = Result<LockResponse>>
+    where
+        R: Into<LockRequest>;
+
+    /// Releases a lock previously acquired via [`lock`](LockOp::lock), given the key it returned.
+    fn unlock<R>(&self, req: R) -> impl Future<Output = Result<UnlockResponse>>
+    where
+        R: Into<UnlockRequest>;
+}
+
+/// Request to acquire a distributed lock.
+#[derive(Clone, Debug)]
+pub struct LockRequest {
+    proto: v3lockpb::LockRequest,
+}
+
+impl LockRequest {
+    /// Creates a new `LockRequest` for the lock named `name`.
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<Vec<u8>>,
+    {
+        Self {
+            proto: v3lockpb::LockRequest {
+                name: name.into(),
+                lease: 0,
+            },
+        }
+    }
+
+    /// Binds the lock to a lease, so it is released automatically if the lease expires.
+    pub fn lease(mut self, lease_id: LeaseId) -> Self {
+        self.proto.lease = lease_id.into();
+        self
+    }
+}
+
+impl From<LockRequest> for v3lockpb::LockRequest {
+    fn from(req: LockRequest) -> Self {
+        req.proto
+    }
+}
+
+impl From<Vec<u8>> for LockRequest {
+    fn from(name: Vec<u8>) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for LockRequest {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Response to a [`LockRequest`].
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct LockResponse {
+    /// The owned key that uniquely identifies this held lock. Pass it to
+    /// [`LockOp::unlock`] to release the lock.
+    pub key: Vec<u8>,
+}
+
+impl From<v3lockpb::LockResponse> for LockResponse {
+    fn from(proto: v3lockpb::LockResponse) -> Self {
+        Self { key: proto.key }
+    }
+}
+
+/// Request to release a held lock.
+#[derive(Clone, Debug)]
+pub struct UnlockRequest {
+    proto: v3lockpb::UnlockRequest,
+}
+
+impl UnlockRequest {
+    /// Creates a new `UnlockRequest` for the key returned by a previous [`LockResponse`].
+    pub fn new<K>(key: K) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self {
+            proto: v3lockpb::UnlockRequest { key: key.into() },
+        }
+    }
+}
+
+impl From<UnlockRequest> for v3lockpb::UnlockRequest {
+    fn from(req: UnlockRequest) -> Self {
+        req.proto
+    }
+}
+
+impl From<Vec<u8>> for UnlockRequest {
+    fn from(key: Vec<u8>) -> Self {
+        Self::new(key)
+    }
+}
+
+impl From<LockResponse> for UnlockRequest {
+    fn from(resp: LockResponse) -> Self {
+        Self::new(resp.key)
+    }
+}
+
+/// Response to an [`UnlockRequest`].
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct UnlockResponse {}
+
+impl From<v3lockpb::UnlockResponse> for UnlockResponse {
+    fn from(_proto: v3lockpb::UnlockResponse) -> Self {
+        Self {}
+    }
+}